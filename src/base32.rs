@@ -0,0 +1,141 @@
+// Compact Crockford Base32 canonical encoding: 13 symbols, MSB-group first.
+
+use arrayvec::ArrayString;
+
+use crate::{NetId64, ParseError};
+
+/// Crockford Base32 alphabet (excludes I, L, O, U to avoid visual ambiguity).
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Number of Base32 symbols needed to cover a 64-bit value 5 bits at a time.
+const SYMBOLS: usize = 13;
+
+impl NetId64 {
+    /// Encode the raw `u64` as 13 Crockford Base32 symbols, most-significant
+    /// group first.
+    pub fn to_base32(self) -> ArrayString<SYMBOLS> {
+        let mut out = ArrayString::<SYMBOLS>::new();
+        for i in (0..SYMBOLS).rev() {
+            let idx = ((self.0 >> (i * 5)) & 0x1F) as usize;
+            out.push(ALPHABET[idx] as char);
+        }
+        out
+    }
+
+    /// Decode a 13-symbol Crockford Base32 string back into an id.
+    ///
+    /// Case-insensitive; `I`/`l` decode as `1` and `O` decodes as `0` to
+    /// tolerate visually ambiguous input. Any other out-of-alphabet symbol,
+    /// or a string that isn't exactly 13 symbols long, is a [`ParseError`].
+    pub fn from_base32(s: &str) -> Result<Self, ParseError> {
+        if s.len() != SYMBOLS {
+            return Err(ParseError);
+        }
+        let mut chars = s.chars();
+        // The first symbol only ever holds the top 4 bits of a u64 (13 * 5 =
+        // 65 bits of symbol space for 64 bits of value); a first symbol > 15
+        // cannot have come from `to_base32` and would otherwise silently
+        // overflow the accumulator below.
+        let first = decode_symbol(chars.next().expect("len checked above")).ok_or(ParseError)?;
+        if first > 0x0F {
+            return Err(ParseError);
+        }
+        let mut v: u64 = first as u64;
+        for c in chars {
+            v = (v << 5) | decode_symbol(c).ok_or(ParseError)? as u64;
+        }
+        Ok(Self(v))
+    }
+}
+
+fn decode_symbol(c: char) -> Option<u8> {
+    Some(match c.to_ascii_uppercase() {
+        '0' => 0,
+        '1' => 1,
+        '2' => 2,
+        '3' => 3,
+        '4' => 4,
+        '5' => 5,
+        '6' => 6,
+        '7' => 7,
+        '8' => 8,
+        '9' => 9,
+        'A' => 10,
+        'B' => 11,
+        'C' => 12,
+        'D' => 13,
+        'E' => 14,
+        'F' => 15,
+        'G' => 16,
+        'H' => 17,
+        'J' => 18,
+        'K' => 19,
+        'M' => 20,
+        'N' => 21,
+        'P' => 22,
+        'Q' => 23,
+        'R' => 24,
+        'S' => 25,
+        'T' => 26,
+        'V' => 27,
+        'W' => 28,
+        'X' => 29,
+        'Y' => 30,
+        'Z' => 31,
+        'I' | 'L' => 1,
+        'O' => 0,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base32_roundtrip() {
+        let id = NetId64::make(1, 0x1234, 0xABCDE);
+        let s = id.to_base32();
+        assert_eq!(s.len(), 13);
+        assert_eq!(NetId64::from_base32(&s).unwrap(), id);
+    }
+
+    #[test]
+    fn base32_is_case_insensitive() {
+        let id = NetId64::make(0xFF, 0xFFFF, 0xFF_FFFF_FFFF);
+        let s = id.to_base32();
+        assert_eq!(NetId64::from_base32(&s.to_lowercase()).unwrap(), id);
+    }
+
+    #[test]
+    fn base32_treats_ambiguous_symbols_as_canonical_digits() {
+        let canonical = "0000000000001";
+        let ambiguous = "OOOOOOOOOOOOI";
+        assert_eq!(
+            NetId64::from_base32(canonical).unwrap(),
+            NetId64::from_base32(ambiguous).unwrap()
+        );
+    }
+
+    #[test]
+    fn base32_rejects_wrong_length_and_bad_symbols() {
+        assert!(NetId64::from_base32("TOOSHORT").is_err());
+        assert!(NetId64::from_base32("UUUUUUUUUUUUU").is_err());
+    }
+
+    #[test]
+    fn base32_rejects_first_symbol_overflowing_64_bits() {
+        // "G" decodes to 16, which can't fit in the first symbol's 4 bits
+        // without wrapping; must be rejected rather than aliasing NetId64(0).
+        assert!(NetId64::from_base32("G000000000000").is_err());
+        assert_eq!(NetId64(0).to_base32().as_str(), "0000000000000");
+    }
+
+    #[test]
+    fn from_str_accepts_base32_form() {
+        let id = NetId64::make(7, 42, 999);
+        let s = id.to_base32();
+        let back: NetId64 = s.parse().unwrap();
+        assert_eq!(back, id);
+    }
+}
@@ -0,0 +1,42 @@
+// Constant-time comparison for trust-boundary use (tokens/handles from untrusted input).
+
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::NetId64;
+
+impl NetId64 {
+    /// Compare two ids without early exit, for use where timing could leak
+    /// information to an attacker supplying one side of the comparison.
+    #[inline]
+    pub fn ct_eq(&self, other: &NetId64) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConstantTimeEq for NetId64 {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ct_eq_matches_derived_eq() {
+        let a = NetId64::make(1, 2, 3);
+        let b = NetId64::make(1, 2, 3);
+        let c = NetId64::make(1, 2, 4);
+        assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+        assert_eq!(bool::from(a.ct_eq(&c)), a == c);
+    }
+
+    #[test]
+    fn constant_time_eq_trait_impl() {
+        let a = NetId64::make(9, 9, 9);
+        let b = NetId64::make(9, 9, 9);
+        assert!(bool::from(ConstantTimeEq::ct_eq(&a, &b)));
+    }
+}
@@ -0,0 +1,104 @@
+// Monotonic NetId64 generator: one atomic 40-bit counter per (kind, node).
+
+use core::fmt;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::NetId64;
+
+/// Mask of the 40 counter bits within a [`NetId64`].
+const COUNTER_MASK: u64 = 0xFF_FFFF_FFFF;
+
+/// Hands out fresh, strictly increasing ids for a fixed `(kind, node)` pair.
+pub struct NetId64Gen {
+    kind: u8,
+    node: u16,
+    counter: AtomicU64,
+}
+
+impl NetId64Gen {
+    /// Start counting from zero.
+    pub fn new(kind: u8, node: u16) -> Self {
+        Self::with_counter(kind, node, 0)
+    }
+
+    /// Resume counting from `start`, e.g. after a restart.
+    pub fn with_counter(kind: u8, node: u16, start: u64) -> Self {
+        Self {
+            kind,
+            node,
+            counter: AtomicU64::new(start & COUNTER_MASK),
+        }
+    }
+
+    /// Atomically allocate the next id, or [`GenError::CounterExhausted`]
+    /// once the 40-bit counter would overflow into the node/kind bits.
+    pub fn next(&self) -> Result<NetId64, GenError> {
+        let c = self.counter.fetch_add(1, Ordering::Relaxed);
+        if c > COUNTER_MASK {
+            return Err(GenError::CounterExhausted);
+        }
+        Ok(NetId64::make(self.kind, self.node, c))
+    }
+
+    /// The id that the next call to [`NetId64Gen::next`] would hand out,
+    /// without consuming it.
+    pub fn peek(&self) -> NetId64 {
+        let c = self.counter.load(Ordering::Relaxed);
+        NetId64::make(self.kind, self.node, c)
+    }
+}
+
+/// Why [`NetId64Gen::next`] failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenError {
+    /// The 40-bit counter has been exhausted; it would wrap into the node/kind bits.
+    CounterExhausted,
+}
+
+impl fmt::Display for GenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenError::CounterExhausted => f.write_str("NetId64 counter exhausted"),
+        }
+    }
+}
+
+impl std::error::Error for GenError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_increments_counter() {
+        let gen = NetId64Gen::new(1, 42);
+        let a = gen.next().unwrap();
+        let b = gen.next().unwrap();
+        assert_eq!(a.counter(), 0);
+        assert_eq!(b.counter(), 1);
+        assert_eq!(a.kind(), 1);
+        assert_eq!(a.node(), 42);
+    }
+
+    #[test]
+    fn peek_does_not_consume() {
+        let gen = NetId64Gen::new(1, 42);
+        let peeked = gen.peek();
+        let next = gen.next().unwrap();
+        assert_eq!(peeked, next);
+        assert_eq!(gen.peek().counter(), 1);
+    }
+
+    #[test]
+    fn with_counter_resumes_after_restart() {
+        let gen = NetId64Gen::with_counter(1, 42, 100);
+        assert_eq!(gen.next().unwrap().counter(), 100);
+    }
+
+    #[test]
+    fn overflow_is_reported_instead_of_wrapping() {
+        let gen = NetId64Gen::with_counter(1, 42, COUNTER_MASK);
+        assert_eq!(gen.next().unwrap().counter(), COUNTER_MASK);
+        assert_eq!(gen.next(), Err(GenError::CounterExhausted));
+    }
+}
@@ -0,0 +1,203 @@
+// HMAC-SHA256 tamper-evident tokens for handing NetId64 to untrusted clients.
+
+use core::fmt;
+use std::string::String;
+use std::vec::Vec;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+use crate::NetId64;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Default truncated tag length, in bytes.
+pub const DEFAULT_TAG_LEN: usize = 8;
+
+/// Minimum tag length [`SignedNetId64::verify`] will accept. A shorter tag
+/// (including the empty tag of an id with no tag at all) would make forgery
+/// trivial, so it's rejected regardless of what length the caller signed with.
+const MIN_TAG_LEN: usize = 4;
+
+/// An `id_bytes || tag` token: tamper-evident but not confidential.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SignedNetId64 {
+    bytes: Vec<u8>,
+}
+
+impl SignedNetId64 {
+    /// Sign `id` with `key`, truncating the HMAC-SHA256 tag to [`DEFAULT_TAG_LEN`] bytes.
+    pub fn sign(id: NetId64, key: &[u8]) -> Self {
+        Self::sign_with_tag_len(id, key, DEFAULT_TAG_LEN)
+    }
+
+    /// Sign `id` with `key`, truncating the HMAC-SHA256 tag to `tag_len` bytes.
+    pub fn sign_with_tag_len(id: NetId64, key: &[u8], tag_len: usize) -> Self {
+        let id_bytes = id.to_be_bytes();
+        let tag = full_tag(key, &id_bytes);
+        let mut bytes = Vec::with_capacity(id_bytes.len() + tag_len);
+        bytes.extend_from_slice(&id_bytes);
+        bytes.extend_from_slice(&tag[..tag_len.min(tag.len())]);
+        Self { bytes }
+    }
+
+    /// Recompute the HMAC over `bytes[..8]` and compare it to `bytes[8..]` in
+    /// constant time. The full HMAC always runs, even for malformed input, so
+    /// that timing doesn't reveal which check failed; length is checked only
+    /// after, never used to skip the computation.
+    pub fn verify(bytes: &[u8], key: &[u8]) -> Result<NetId64, VerifyError> {
+        let mut id_bytes = [0u8; 8];
+        let id_len = bytes.len().min(id_bytes.len());
+        id_bytes[..id_len].copy_from_slice(&bytes[..id_len]);
+        let computed = full_tag(key, &id_bytes);
+
+        if bytes.len() < 8 + MIN_TAG_LEN {
+            return Err(VerifyError::TooShort);
+        }
+        let tag = &bytes[8..];
+        if tag.len() > computed.len() {
+            return Err(VerifyError::BadTag);
+        }
+        let ok: bool = computed[..tag.len()].ct_eq(tag).into();
+        if !ok {
+            return Err(VerifyError::BadTag);
+        }
+        Ok(NetId64::from_be_bytes(id_bytes))
+    }
+
+    /// The raw `id_bytes || tag` token.
+    pub fn to_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Wrap a raw `id_bytes || tag` token without verifying it.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    /// Lowercase hex encoding of the token, suitable for URLs/cookies.
+    pub fn to_hex(&self) -> String {
+        use core::fmt::Write;
+        let mut s = String::with_capacity(self.bytes.len() * 2);
+        for b in &self.bytes {
+            let _ = write!(s, "{b:02x}");
+        }
+        s
+    }
+
+    /// Parse a token previously produced by [`SignedNetId64::to_hex`].
+    pub fn from_hex(s: &str) -> Result<Self, VerifyError> {
+        if !s.len().is_multiple_of(2) {
+            return Err(VerifyError::TooShort);
+        }
+        let mut bytes = Vec::with_capacity(s.len() / 2);
+        for i in (0..s.len()).step_by(2) {
+            let byte = u8::from_str_radix(&s[i..i + 2], 16).map_err(|_| VerifyError::BadTag)?;
+            bytes.push(byte);
+        }
+        Ok(Self { bytes })
+    }
+}
+
+fn full_tag(key: &[u8], id_bytes: &[u8]) -> [u8; 32] {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC-SHA256 accepts any key length");
+    mac.update(id_bytes);
+    mac.finalize().into_bytes().into()
+}
+
+/// Why a token failed to verify.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyError {
+    /// Input was shorter than the 8-byte id it must carry.
+    TooShort,
+    /// The tag (or its hex encoding) did not match the recomputed HMAC.
+    BadTag,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::TooShort => f.write_str("signed token too short"),
+            VerifyError::BadTag => f.write_str("signed token tag mismatch"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VerifyError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_then_verify_roundtrips() {
+        let id = NetId64::make(3, 7, 11);
+        let key = b"super-secret-key";
+        let signed = SignedNetId64::sign(id, key);
+        let back = SignedNetId64::verify(signed.to_bytes(), key).unwrap();
+        assert_eq!(back, id);
+    }
+
+    #[test]
+    fn tampered_id_bytes_fail_verification() {
+        let id = NetId64::make(3, 7, 11);
+        let key = b"super-secret-key";
+        let mut signed = SignedNetId64::sign(id, key).to_bytes().to_vec();
+        signed[0] ^= 0xFF;
+        assert_eq!(
+            SignedNetId64::verify(&signed, key),
+            Err(VerifyError::BadTag)
+        );
+    }
+
+    #[test]
+    fn wrong_key_fails_verification() {
+        let id = NetId64::make(3, 7, 11);
+        let signed = SignedNetId64::sign(id, b"key-a");
+        assert_eq!(
+            SignedNetId64::verify(signed.to_bytes(), b"key-b"),
+            Err(VerifyError::BadTag)
+        );
+    }
+
+    #[test]
+    fn too_short_input_is_rejected() {
+        assert_eq!(
+            SignedNetId64::verify(&[0u8; 4], b"key"),
+            Err(VerifyError::TooShort)
+        );
+    }
+
+    #[test]
+    fn bare_id_bytes_with_no_tag_are_rejected() {
+        let id = NetId64::make(3, 7, 11);
+        assert_eq!(
+            SignedNetId64::verify(&id.to_be_bytes(), b"any-key"),
+            Err(VerifyError::TooShort)
+        );
+    }
+
+    #[test]
+    fn tag_shorter_than_min_tag_len_is_rejected() {
+        let id = NetId64::make(3, 7, 11);
+        let key = b"super-secret-key";
+        let short = SignedNetId64::sign_with_tag_len(id, key, MIN_TAG_LEN - 1);
+        assert_eq!(
+            SignedNetId64::verify(short.to_bytes(), key),
+            Err(VerifyError::TooShort)
+        );
+    }
+
+    #[test]
+    fn hex_roundtrip() {
+        let id = NetId64::make(1, 2, 3);
+        let signed = SignedNetId64::sign(id, b"key");
+        let hex = signed.to_hex();
+        let back = SignedNetId64::from_hex(&hex).unwrap();
+        assert_eq!(back.to_bytes(), signed.to_bytes());
+    }
+}
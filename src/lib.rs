@@ -3,6 +3,24 @@
 
 use core::{fmt, str::FromStr};
 
+mod base32;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "ct")]
+mod ct;
+
+#[cfg(feature = "hmac")]
+mod hmac_token;
+#[cfg(feature = "hmac")]
+pub use hmac_token::{SignedNetId64, VerifyError as SignedVerifyError};
+
+#[cfg(feature = "std")]
+mod gen;
+#[cfg(feature = "std")]
+pub use gen::{GenError, NetId64Gen};
+
 /// 64-bit ID layout: [KIND:8][NODE:16][COUNTER:40] big-endian semantics.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NetId64(pub u64);
@@ -75,12 +93,16 @@ impl fmt::Debug for NetId64 {
 impl FromStr for NetId64 {
     type Err = ParseError;
 
-    /// Accepts "k:node:ctr" (decimal) or "0x..." (raw u64).
+    /// Accepts "k:node:ctr" (decimal), "0x..." (raw u64), or a 13-symbol
+    /// Crockford Base32 canonical string.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Some(hex) = s.strip_prefix("0x") {
             let v = u64::from_str_radix(hex, 16).map_err(|_| ParseError)?;
             return Ok(Self(v));
         }
+        if !s.contains(':') {
+            return Self::from_base32(s);
+        }
         let mut it = s.split(':');
         let k = it
             .next()
@@ -135,6 +157,14 @@ mod tests {
         assert_eq!(id.kind(), 7);
         assert_eq!(id.node(), 42);
         assert_eq!(id.counter(), 999);
+    }
+
+    // `to_string`/`format!` need an allocator; keep the no_std core testable
+    // without one by splitting these off behind the `std` feature.
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_and_display_std_formatting() {
+        let id: NetId64 = "7:42:999".parse().unwrap();
         assert_eq!(id.to_string(), "7:42:999");
         let hex = format!("{id:?}"); // Debug prints hex too
         assert!(hex.contains("0x"));
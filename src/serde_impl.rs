@@ -0,0 +1,97 @@
+// Serde support for `NetId64`: human-readable triple string, binary raw bytes.
+
+use core::str::FromStr;
+
+use serde::de::{Error as DeError, Visitor};
+use serde::{Deserializer, Serializer};
+
+use crate::NetId64;
+
+impl serde::Serialize for NetId64 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.to_be_bytes())
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for NetId64 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            struct TripleVisitor;
+
+            impl<'de> Visitor<'de> for TripleVisitor {
+                type Value = NetId64;
+
+                fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str(r#"a "k:node:ctr" string or "0x..." hex string"#)
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: DeError,
+                {
+                    NetId64::from_str(v).map_err(E::custom)
+                }
+            }
+
+            deserializer.deserialize_str(TripleVisitor)
+        } else {
+            struct BytesVisitor;
+
+            impl<'de> Visitor<'de> for BytesVisitor {
+                type Value = NetId64;
+
+                fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str("8 big-endian bytes")
+                }
+
+                fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+                where
+                    E: DeError,
+                {
+                    let b: [u8; 8] = v.try_into().map_err(|_| E::invalid_length(v.len(), &self))?;
+                    Ok(NetId64::from_be_bytes(b))
+                }
+            }
+
+            deserializer.deserialize_bytes(BytesVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_roundtrip_is_triple_string() {
+        let id = NetId64::make(7, 42, 999);
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"7:42:999\"");
+        let back: NetId64 = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, id);
+    }
+
+    #[test]
+    fn json_accepts_hex_form() {
+        let id: NetId64 = serde_json::from_str("\"0x0000000000ABCDE0\"").unwrap();
+        assert_eq!(id.raw(), 0x0000000000ABCDE0);
+    }
+
+    #[test]
+    fn bincode_roundtrip_is_raw_bytes() {
+        let id = NetId64::make(1, 0x1234, 0xABCDE);
+        let bytes = bincode::serialize(&id).unwrap();
+        let back: NetId64 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back, id);
+    }
+}